@@ -1,7 +1,13 @@
 use pyo3::prelude::*;
+use rayon::prelude::*;
+use std::collections::VecDeque;
+use std::sync::Mutex;
 
+use crate::decision_log::*;
 use crate::entities::*;
 use crate::errors::*;
+use crate::partial_request::*;
+use crate::partial_response::*;
 use crate::policy_set::*;
 use crate::request::*;
 use crate::response::*;
@@ -18,45 +24,92 @@ use crate::schema::*;
 /// Parameters:
 ///     policies: the policies to used when checking authorization
 ///     schema: the schema used to verify policies, entities and requests
+///     mode: the validation mode to apply when a schema is provided, defaults to [Strict][cedar.ValidationMode.Strict]
+///     reject_invalid_policies: when false, an invalid policy set does not
+///         raise: the authorizer is still constructed, and the validation
+///         result (including warnings) is kept accessible via
+///         [validation_result][cedar.Authorizer.validation_result], so the
+///         validator can be run as a linting pass instead of a hard gate
+///     log_capacity: when set, every decision is appended to an in-memory
+///         ring buffer of at most this many entries, which can be drained
+///         with [pop_logs][cedar.Authorizer.pop_logs] or looked up with
+///         [get_log_by_id][cedar.Authorizer.get_log_by_id]. Logging is
+///         disabled by default.
 #[pyclass(module = "cedar._lib")]
 pub struct Authorizer {
     policies: PolicySet,
     schema: Option<Schema>,
     authorizer: cedar_policy::Authorizer,
+    validation_result: Option<ValidationResult>,
+    logs: Option<Mutex<VecDeque<DecisionLogEntry>>>,
+    log_capacity: usize,
 }
 
 impl Authorizer {
     fn new(
         policies: Option<PolicySet>,
         schema: Option<Schema>,
+        mode: Option<ValidationMode>,
+        reject_invalid_policies: bool,
+        log_capacity: Option<usize>,
     ) -> Result<Authorizer, ValidationResult> {
         let authorizer = cedar_policy::Authorizer::new();
         let policies = policies.unwrap_or(PolicySet {
             policy_set: cedar_policy::PolicySet::new(),
         });
-        match schema.as_ref() {
-            Some(schema) => {
-                let result = schema.validate_policies(&policies);
-                if !result.passed {
-                    return Err(result);
-                }
+        let validation_result = schema
+            .as_ref()
+            .map(|schema| schema.validate_policies(&policies, mode));
+        if let Some(result) = &validation_result {
+            if !result.passed && reject_invalid_policies {
+                return Err(result.clone());
             }
-            None => (),
         }
         Ok(Authorizer {
             policies,
             schema,
             authorizer,
+            validation_result,
+            logs: log_capacity.map(|_| Mutex::new(VecDeque::new())),
+            log_capacity: log_capacity.unwrap_or(0),
         })
     }
+
+    /// Append a decision to the log, if logging is enabled, evicting the
+    /// oldest entry once the configured capacity is exceeded.
+    fn record_log(&self, request: &Request, response: &Response) {
+        if let Some(logs) = &self.logs {
+            let diagnostics = response.diagnostics();
+            let entry = DecisionLogEntry::new(
+                request.correlation_id.clone(),
+                request.principal.clone(),
+                request.action.clone(),
+                request.resource.clone(),
+                response.decision(),
+                diagnostics.reasons().into_iter().collect(),
+                diagnostics.errors(),
+            );
+            let mut guard = logs.lock().unwrap();
+            guard.push_back(entry);
+            while guard.len() > self.log_capacity {
+                guard.pop_front();
+            }
+        }
+    }
 }
 
 #[pymethods]
 impl Authorizer {
     #[new]
-    #[pyo3(signature = (policies = None, schema = None))]
-    fn new_py(policies: Option<PolicySet>, schema: Option<Schema>) -> PyResult<Authorizer> {
-        Self::new(policies, schema).or_else(|result| {
+    #[pyo3(signature = (policies = None, schema = None, mode = None, reject_invalid_policies = true, log_capacity = None))]
+    fn new_py(
+        policies: Option<PolicySet>,
+        schema: Option<Schema>,
+        mode: Option<ValidationMode>,
+        reject_invalid_policies: bool,
+        log_capacity: Option<usize>,
+    ) -> PyResult<Authorizer> {
+        Self::new(policies, schema, mode, reject_invalid_policies, log_capacity).or_else(|result| {
             let errors: Vec<String> = result
                 .errors
                 .into_iter()
@@ -67,6 +120,17 @@ impl Authorizer {
         })
     }
 
+    /// Get the result of validating the policy set against the schema at
+    /// construction time, if a schema was provided.
+    ///
+    /// When a schema was provided and `reject_invalid_policies=False` was
+    /// used, this is populated even when validation failed, so callers can inspect
+    /// warnings and errors as a linting pass rather than a hard gate.
+    #[getter]
+    fn validation_result(&self) -> Option<ValidationResult> {
+        self.validation_result.clone()
+    }
+
     /// Check if principal is authorized to perform action on resource within context.
     ///
     /// Parameters:
@@ -85,23 +149,27 @@ impl Authorizer {
         let response =
             self.authorizer
                 .is_authorized(&cedar_request, &self.policies.policy_set, &entities);
-        Ok(Response::from_cedar_response(
-            response,
-            request.correlation_id.clone(),
-        ))
+        let response = Response::from_cedar_response(response, request.correlation_id.clone());
+        self.record_log(request, &response);
+        Ok(response)
     }
 
     /// Check if list of requests are authorized.
     ///
+    /// The policy set, entities and schema are all immutable during
+    /// evaluation, so the batch is evaluated in parallel across a worker
+    /// pool with the GIL released, then reassembled in input order.
+    ///
     /// Parameters:
     ///     requests: a list of requests describing principals, actions, resources and contexts
     ///     entities: the entities to consider when applying policies
     ///
     /// Returns:
-    ///     A list of authorization responses
+    ///     A list of authorization responses, in the same order as `requests`
     #[pyo3(signature = (requests, entities = None))]
     fn is_authorized_batch(
         &self,
+        py: Python<'_>,
         requests: Vec<Request>,
         entities: Option<&Entities>,
     ) -> PyResult<Vec<Response>> {
@@ -109,19 +177,101 @@ impl Authorizer {
         let entities = entities
             .unwrap_or(&Entities::empty())
             .make_cedar_entities(schema)?;
-        let mut responses: Vec<Response> = Vec::new();
-        for request in requests {
-            let cedar_request = request.make_cedar_request(schema)?;
-            let response =
-                self.authorizer
-                    .is_authorized(&cedar_request, &self.policies.policy_set, &entities);
-            responses.push(Response::from_cedar_response(
-                response,
-                request.correlation_id.clone(),
-            ));
+        let cedar_requests: Vec<cedar_policy::Request> = requests
+            .iter()
+            .map(|request| request.make_cedar_request(schema))
+            .collect::<PyResult<_>>()?;
+        let authorizer = &self.authorizer;
+        let policy_set = &self.policies.policy_set;
+        let responses: Vec<Response> = py.allow_threads(|| {
+            cedar_requests
+                .par_iter()
+                .zip(requests.par_iter())
+                .map(|(cedar_request, request)| {
+                    let response = authorizer.is_authorized(cedar_request, policy_set, &entities);
+                    Response::from_cedar_response(response, request.correlation_id.clone())
+                })
+                .collect()
+        });
+        // Responses come back in input order (par_iter over a Vec preserves
+        // index order), so logging sequentially afterwards keeps the audit
+        // trail - and which entries survive the ring buffer's eviction -
+        // deterministic, rather than racing across worker threads.
+        for (request, response) in requests.iter().zip(responses.iter()) {
+            self.record_log(request, response);
         }
         Ok(responses)
     }
+
+    /// Partially authorize a request where the principal, action, resource
+    /// and/or context may be left unknown.
+    ///
+    /// Every policy is evaluated as far as possible given the known
+    /// fields. If the decision can already be determined it is returned
+    /// as-is; otherwise the policies that still depend on an unknown are
+    /// returned as residuals that can be stored and re-evaluated once the
+    /// unknowns are filled in.
+    ///
+    /// Parameters:
+    ///     request: a partial request, with unknown fields left as None
+    ///     entities: the entities to consider when applying policies
+    ///
+    /// Returns:
+    ///     A partial authorization response
+    ///
+    /// See also:
+    ///     * <https://docs.cedarpolicy.com/auth/entities-syntax.html#request>
+    #[pyo3(signature = (request, entities = None))]
+    fn is_authorized_partial(
+        &self,
+        request: &PartialRequest,
+        entities: Option<&Entities>,
+    ) -> PyResult<PartialResponse> {
+        let schema = self.schema.as_ref();
+        let entities = entities
+            .unwrap_or(&Entities::empty())
+            .make_cedar_entities(schema)?;
+        let cedar_request = request.make_cedar_request(schema)?;
+        let response = self.authorizer.is_authorized_partial(
+            &cedar_request,
+            &self.policies.policy_set,
+            &entities,
+        );
+        Ok(PartialResponse::from_cedar_partial_response(
+            response,
+            request.correlation_id.clone(),
+        ))
+    }
+
+    /// Drain and return every entry recorded in the decision log.
+    ///
+    /// Returns:
+    ///     A list of decision log entries, oldest first
+    fn pop_logs(&self) -> PyResult<Vec<DecisionLogEntry>> {
+        let logs = self.logs.as_ref().or_value_error(
+            "decision log is not enabled, construct Authorizer with a log_capacity",
+        )?;
+        let mut guard = logs.lock().unwrap();
+        Ok(std::mem::take(&mut *guard).into())
+    }
+
+    /// Look up a single decision log entry by its correlation id, without draining the log.
+    ///
+    /// Parameters:
+    ///     correlation_id: the correlation id of the request to look up
+    ///
+    /// Returns:
+    ///     The matching log entry, or None if no entry has that correlation id
+    fn get_log_by_id(&self, correlation_id: &str) -> PyResult<Option<DecisionLogEntry>> {
+        let logs = self.logs.as_ref().or_value_error(
+            "decision log is not enabled, construct Authorizer with a log_capacity",
+        )?;
+        let guard = logs.lock().unwrap();
+        Ok(guard
+            .iter()
+            .find(|entry| entry.correlation_id().as_deref() == Some(correlation_id))
+            .cloned())
+    }
 }
 
 /// Check if principal is authorized to perform action on resource within context.
@@ -154,18 +304,54 @@ pub fn is_authorized(
     ))
 }
 
+/// Partially authorize a request where the principal, action, resource
+/// and/or context may be left unknown.
+///
+/// Parameters:
+///     request: a partial request, with unknown fields left as None
+///     policies: the policies to apply when checking authorization
+///     entities: the entities to consider when applying policies
+///     schema: an optional schema used to validate known request fields
+///
+/// Returns:
+///     A partial authorization response
+#[pyfunction]
+#[pyo3(signature = (request, policies, entities = None, schema = None))]
+pub fn is_authorized_partial(
+    request: &PartialRequest,
+    policies: &PolicySet,
+    entities: Option<&Entities>,
+    schema: Option<&Schema>,
+) -> PyResult<PartialResponse> {
+    let authorizer = cedar_policy::Authorizer::new();
+    let entities = entities
+        .unwrap_or(&Entities::empty())
+        .make_cedar_entities(schema)?;
+    let policy_set = &policies.policy_set;
+    let cedar_request = request.make_cedar_request(schema)?;
+    let response = authorizer.is_authorized_partial(&cedar_request, policy_set, &entities);
+    Ok(PartialResponse::from_cedar_partial_response(
+        response,
+        request.correlation_id.clone(),
+    ))
+}
+
 /// Check if list of requests are authorized.
 ///
+/// The batch is evaluated in parallel across a worker pool with the GIL
+/// released, then reassembled in input order.
+///
 /// Parameters:
 ///     requests: a list of authorization requests
 ///     policies: the policies to apply when checking authorization
 ///     entities: the entities to consider when applying policies
 ///
 /// Returns:
-///     A list of authorization responses
+///     A list of authorization responses, in the same order as `requests`
 #[pyfunction]
 #[pyo3(signature = (requests, policies, entities = None, schema = None))]
 pub fn is_authorized_batch(
+    py: Python<'_>,
     requests: Vec<Request>,
     policies: PolicySet,
     entities: Option<&Entities>,
@@ -176,14 +362,19 @@ pub fn is_authorized_batch(
     let entities = entities
         .unwrap_or(&Entities::empty())
         .make_cedar_entities(schema)?;
-    let mut responses: Vec<Response> = Vec::new();
-    for request in requests {
-        let cedar_request = request.make_cedar_request(schema)?;
-        let response = authorizer.is_authorized(&cedar_request, policy_set, &entities);
-        responses.push(Response::from_cedar_response(
-            response,
-            request.correlation_id.clone(),
-        ));
-    }
+    let cedar_requests: Vec<cedar_policy::Request> = requests
+        .iter()
+        .map(|request| request.make_cedar_request(schema))
+        .collect::<PyResult<_>>()?;
+    let responses: Vec<Response> = py.allow_threads(|| {
+        cedar_requests
+            .par_iter()
+            .zip(requests.par_iter())
+            .map(|(cedar_request, request)| {
+                let response = authorizer.is_authorized(cedar_request, policy_set, &entities);
+                Response::from_cedar_response(response, request.correlation_id.clone())
+            })
+            .collect()
+    });
     Ok(responses)
 }
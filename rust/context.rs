@@ -0,0 +1,105 @@
+use pyo3::{prelude::*, types::PyDict};
+use serde::{Deserialize, Serialize};
+
+use crate::{entity_uid::EntityUid, errors::IntoPyErr, schema::Schema};
+
+/// Context for an authorization request.
+///
+/// The context is a (possibly empty) record of additional information
+/// that is not part of the principal, action or resource, but that
+/// policies may still want to reason about (e.g. the time of day, or
+/// whether multi-factor authentication was used).
+///
+/// See also:
+///     * <https://docs.cedarpolicy.com/auth/entities-syntax.html#context>
+#[pyclass(module = "cedar._lib")]
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Context {
+    value: serde_json::Value,
+}
+
+impl Context {
+    pub fn make_cedar_context(
+        &self,
+        schema: Option<&Schema>,
+        action: &EntityUid,
+    ) -> PyResult<cedar_policy::Context> {
+        self.make_cedar_context_for_action(schema, Some(action))
+    }
+
+    /// Build a `cedar_policy::Context`, skipping schema-based validation when
+    /// the action is not yet known (as can happen during partial evaluation).
+    pub fn make_cedar_context_for_action(
+        &self,
+        schema: Option<&Schema>,
+        action: Option<&EntityUid>,
+    ) -> PyResult<cedar_policy::Context> {
+        let action_euid = action.map(|a| a.make_cedar_euid()).transpose()?;
+        let cedar_schema = schema.map(|s| &s.schema);
+        cedar_policy::Context::from_json_value(
+            self.value.clone(),
+            cedar_schema.zip(action_euid.as_ref()),
+        )
+        .or_value_error("failed to parse context")
+    }
+
+    pub fn empty() -> Self {
+        Context {
+            value: serde_json::Value::Object(serde_json::Map::new()),
+        }
+    }
+}
+
+#[pymethods]
+impl Context {
+    /// Create a context from a JSON string.
+    ///
+    /// Parameters:
+    ///     text: a string in JSON format, holding a (possibly empty) map from keys to values
+    ///
+    /// Returns:
+    ///     A context
+    ///
+    /// See also:
+    ///     * <https://docs.cedarpolicy.com/auth/entities-syntax.html#context>
+    #[staticmethod]
+    fn from_json(text: &str) -> PyResult<Self> {
+        serde_json::from_str(text)
+            .or_value_error("failed to parse context from JSON")
+            .map(|value| Context { value })
+    }
+
+    /// Create a context from a python dictionary.
+    ///
+    /// Parameters:
+    ///     values: a dict holding the context attributes
+    ///
+    /// Returns:
+    ///     A context
+    ///
+    /// See also:
+    ///     * <https://docs.cedarpolicy.com/auth/entities-syntax.html#context>
+    #[staticmethod]
+    pub fn from_dict(values: &Bound<'_, PyDict>) -> PyResult<Self> {
+        pythonize::depythonize(values)
+            .or_value_error("failed to parse dict")
+            .map(|value| Context { value })
+    }
+
+    /// Serialize context to a python dictionary.
+    ///
+    /// Returns:
+    ///     A python dictionary
+    pub fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        pythonize::pythonize(py, &self.value).or_value_error("failed to serialize context to dict")
+    }
+
+    /// Serialize context to a JSON string.
+    ///
+    /// Returns:
+    ///     A string in JSON format
+    fn to_json(&self) -> PyResult<String> {
+        serde_json::to_string(&self.value).or_value_error("failed to serialize context to JSON")
+    }
+}
@@ -0,0 +1,120 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::entity_uid::EntityUid;
+use crate::errors::*;
+use crate::response::Decision;
+
+/// A single entry recorded in an [Authorizer][cedar.Authorizer] decision log.
+///
+/// Parameters:
+///     correlation_id: the correlation id of the originating request, if any
+///     principal: the principal from the originating request
+///     action: the action from the originating request
+///     resource: the resource from the originating request
+///     decision: the decision that was reached
+///     reasons: ids of the policies that determined the decision
+///     errors: evaluation errors encountered while reaching the decision
+///     timestamp: unix timestamp, in seconds, at which the decision was recorded
+#[pyclass(module = "cedar._lib")]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct DecisionLogEntry {
+    correlation_id: Option<String>,
+    principal: EntityUid,
+    action: EntityUid,
+    resource: EntityUid,
+    decision: Decision,
+    reasons: Vec<String>,
+    errors: Vec<String>,
+    timestamp: f64,
+}
+
+impl DecisionLogEntry {
+    pub fn new(
+        correlation_id: Option<String>,
+        principal: EntityUid,
+        action: EntityUid,
+        resource: EntityUid,
+        decision: Decision,
+        reasons: Vec<String>,
+        errors: Vec<String>,
+    ) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs_f64())
+            .unwrap_or(0.0);
+        DecisionLogEntry {
+            correlation_id,
+            principal,
+            action,
+            resource,
+            decision,
+            reasons,
+            errors,
+            timestamp,
+        }
+    }
+}
+
+#[pymethods]
+impl DecisionLogEntry {
+    /// Get the correlation id of the originating request, if any.
+    #[getter]
+    pub fn correlation_id(&self) -> Option<String> {
+        self.correlation_id.clone()
+    }
+
+    /// Get the principal from the originating request.
+    #[getter]
+    fn principal(&self) -> EntityUid {
+        self.principal.clone()
+    }
+
+    /// Get the action from the originating request.
+    #[getter]
+    fn action(&self) -> EntityUid {
+        self.action.clone()
+    }
+
+    /// Get the resource from the originating request.
+    #[getter]
+    fn resource(&self) -> EntityUid {
+        self.resource.clone()
+    }
+
+    /// Get the decision that was reached.
+    #[getter]
+    fn decision(&self) -> Decision {
+        self.decision.clone()
+    }
+
+    /// Get the ids of the policies that determined the decision.
+    #[getter]
+    fn reasons(&self) -> Vec<String> {
+        self.reasons.clone()
+    }
+
+    /// Get the evaluation errors encountered while reaching the decision.
+    #[getter]
+    fn errors(&self) -> Vec<String> {
+        self.errors.clone()
+    }
+
+    /// Get the unix timestamp, in seconds, at which the decision was recorded.
+    #[getter]
+    fn timestamp(&self) -> f64 {
+        self.timestamp
+    }
+
+    /// Serialize the log entry to a python dictionary.
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        pythonize::pythonize(py, self).or_value_error("failed to serialize to dict")
+    }
+
+    /// Serialize the log entry to a JSON string.
+    fn to_json(&self) -> PyResult<String> {
+        serde_json::to_string(&self).or_value_error("failed to serialize to JSON")
+    }
+}
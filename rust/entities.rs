@@ -108,9 +108,9 @@ impl Entities {
         }
         let cedar_entities = cedar_policy::Entities::from_entities(entities, cedar_schema)
             .or_value_error("failed to parse entities")?;
-        return Ok(Entities {
+        Ok(Entities {
             entities: cedar_entities,
-        });
+        })
     }
 
     /// Serialize entities to JSON string.
@@ -59,7 +59,7 @@ impl EntityUid {
     #[staticmethod]
     #[pyo3(signature = (text, /))]
     fn from_json(text: &str) -> PyResult<Self> {
-        serde_json::from_str(&text)
+        serde_json::from_str(text)
             .or_value_error("failed to parse json")
             .and_then(|value| {
                 cedar_policy::EntityUid::from_json(value)
@@ -98,7 +98,7 @@ impl EntityUid {
     #[staticmethod]
     #[pyo3(signature = (text, /))]
     fn from_string(text: &str) -> PyResult<Self> {
-        cedar_policy::EntityUid::from_str(&text)
+        cedar_policy::EntityUid::from_str(text)
             .map(|e| Self::from_cedar_entity_uid(&e))
             .or_value_error("failed to parse entity uid")
     }
@@ -114,8 +114,8 @@ impl EntityUid {
     #[staticmethod]
     fn from_type_name_and_id(name: &str, id: &str) -> PyResult<Self> {
         // Parsing entity id never fails
-        let eid = cedar_policy::EntityId::from_str(&id).unwrap_or_else(|never| match never {});
-        cedar_policy::EntityTypeName::from_str(&name)
+        let eid = cedar_policy::EntityId::from_str(id).unwrap_or_else(|never| match never {});
+        cedar_policy::EntityTypeName::from_str(name)
             .map(|etn| cedar_policy::EntityUid::from_type_name_and_id(etn, eid))
             .map(|euid| Self::from_cedar_entity_uid(&euid))
             .or_value_error("failed to parse entity uid")
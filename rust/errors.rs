@@ -12,7 +12,7 @@ impl<T> IntoPyErr<T> for Option<T> {
     ///
     /// Example:
     ///
-    /// ```
+    /// ```ignore
     /// let err = None.or_value_error("something went wrong")
     /// ```
     ///
@@ -33,7 +33,7 @@ impl<T, E: ToString> IntoPyErr<T> for Result<T, E> {
     ///
     /// Example:
     ///
-    /// ```
+    /// ```ignore
     /// let err = Err("BOOM").or_value_error("something went wrong")
     /// ```
     ///
@@ -24,7 +24,7 @@ pub fn format_policies(
     indent_width: Option<isize>,
 ) -> PyResult<String> {
     cedar_policy_formatter::policies_str_to_pretty(
-        &text,
+        text,
         &cedar_policy_formatter::Config {
             line_width: line_width.unwrap_or(88),
             indent_width: indent_width.unwrap_or(2),
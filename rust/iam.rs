@@ -0,0 +1,170 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use serde::Deserialize;
+
+use crate::errors::*;
+use crate::policy::Policy;
+use crate::policy_set::PolicySet;
+
+/// An AWS IAM/statement-style authorization rule, as found in an IAM policy document.
+#[derive(Deserialize)]
+struct IamStatement {
+    sid: Option<String>,
+    effect: String,
+    actions: Vec<String>,
+    resources: Vec<String>,
+}
+
+/// Build the Cedar text for either the `action` or `resource` scope element
+/// of a policy translated from an IAM statement.
+///
+/// `"*"` maps to an unconstrained scope. Cedar's `like` operator only
+/// applies to strings, not entity ids, so a scope variable can't be
+/// pattern-matched: prefix patterns (e.g. `"s3:GetObject*"`) are rejected
+/// with a clear error instead of silently compiling into a policy that
+/// would raise a type error (and never match) at evaluation time.
+///
+/// Parameters:
+///     var: the scope variable, `"action"` or `"resource"`
+///     default_type: the Cedar entity type to assume for bare (non `Type::"id"`) entries
+///     entries: the IAM action/resource identifiers, `"*"` meaning unconstrained
+fn build_scope_clause(var: &str, default_type: &str, entries: &[String]) -> PyResult<String> {
+    if entries.iter().any(|entry| entry == "*") {
+        return Ok(var.to_string());
+    }
+    if let Some(pattern) = entries.iter().find(|entry| entry.contains('*')) {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "IAM prefix pattern \"{pattern}\" is not supported: Cedar's `like` operator \
+             applies to strings, not entity ids, so a {var} scope can't be pattern-matched"
+        )));
+    }
+    let euids: Vec<String> = entries
+        .iter()
+        .map(|entry| to_euid_text(default_type, entry))
+        .collect();
+    Ok(match euids.as_slice() {
+        [one] => format!("{var} == {one}"),
+        many => format!("{var} in [{}]", many.join(", ")),
+    })
+}
+
+/// Render an IAM action/resource identifier as Cedar entity uid text,
+/// assuming `default_type` when the identifier isn't already a fully
+/// qualified `Type::"id"` reference. Real-world identifiers (e.g. AWS
+/// ARNs such as `arn:aws:s3:::my_bucket`) routinely contain `::`, so that
+/// alone can't be used to detect an already-qualified reference: only an
+/// entry that actually ends in a quoted `Type::"id"` suffix is passed
+/// through verbatim, everything else is quoted and escaped as the id.
+fn to_euid_text(default_type: &str, entry: &str) -> String {
+    if let Some(prefix_end) = entry.rfind("::\"") {
+        if prefix_end > 0 && entry.ends_with('"') && entry.len() > prefix_end + 3 {
+            return entry.to_string();
+        }
+    }
+    format!("{default_type}::{}", quote_cedar_string(entry))
+}
+
+/// Quote and escape a raw string as a Cedar string literal.
+fn quote_cedar_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Translate a single IAM statement into a Cedar policy.
+fn statement_to_policy(statement: IamStatement) -> PyResult<Policy> {
+    let effect = match statement.effect.to_lowercase().as_str() {
+        "allow" => "permit",
+        "deny" => "forbid",
+        other => {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "unknown IAM effect: {other}"
+            )))
+        }
+    };
+    let action_scope = build_scope_clause("action", "Action", &statement.actions)?;
+    let resource_scope = build_scope_clause("resource", "Resource", &statement.resources)?;
+    let text = format!("{effect}(principal, {action_scope}, {resource_scope});");
+    Policy::from_string(&text, statement.sid)
+}
+
+#[pymethods]
+impl Policy {
+    /// Create a policy from an AWS IAM/statement-style JSON object.
+    ///
+    /// Translates the common `{"sid", "effect": "allow"|"deny", "actions": [...],
+    /// "resources": [...]}` shape into an equivalent Cedar policy: each
+    /// statement becomes a `permit`/`forbid` policy whose action and
+    /// resource scopes are built from the listed identifiers, with `*`
+    /// mapping to an unconstrained scope. `sid` is optional and, when
+    /// present, is carried over as the policy id.
+    ///
+    /// Parameters:
+    ///     values: a dict in IAM statement shape
+    ///
+    /// Returns:
+    ///     A policy
+    ///
+    /// Note:
+    ///     Partial-wildcard identifiers (e.g. `"s3:GetObject*"`) are
+    ///     rejected rather than translated into a `like` condition: Cedar's
+    ///     `like` operator only accepts a `String`, and `action`/`resource`
+    ///     in the policy scope are entity uids, not strings, so there is no
+    ///     sound Cedar expression for "this entity's id matches this
+    ///     pattern" without entity data (attributes) that isn't available
+    ///     at import time. Rejecting the statement up front is deliberate:
+    ///     silently dropping or relaxing the pattern would either produce a
+    ///     `permit` policy that's broader than the source IAM statement
+    ///     (a privilege escalation) or a `forbid` policy that's narrower
+    ///     than intended (a lost restriction). Only the literal `"*"`
+    ///     wildcard is supported.
+    #[staticmethod]
+    fn from_iam_statement(values: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let statement: IamStatement = pythonize::depythonize(values)
+            .or_value_error("failed to parse IAM statement from dict")?;
+        statement_to_policy(statement)
+    }
+}
+
+#[pymethods]
+impl PolicySet {
+    /// Create a policy set from an AWS IAM policy document.
+    ///
+    /// Parameters:
+    ///     text: a string in JSON format, either `{"Statement": [...]}` or a bare list of statements
+    ///
+    /// Returns:
+    ///     A policy set holding one policy per statement
+    ///
+    /// See also:
+    ///     * [Policy.from_iam_statement][cedar.Policy.from_iam_statement]
+    ///
+    /// Note:
+    ///     Fails the whole import if any statement uses a partial-wildcard
+    ///     action/resource identifier (e.g. `"s3:GetObject*"`); see
+    ///     [Policy.from_iam_statement][cedar.Policy.from_iam_statement] for why.
+    #[staticmethod]
+    fn from_iam_document(text: &str) -> PyResult<Self> {
+        let document: serde_json::Value =
+            serde_json::from_str(text).or_value_error("failed to parse IAM document from JSON")?;
+        let statements_json = match &document {
+            serde_json::Value::Array(_) => document.clone(),
+            serde_json::Value::Object(map) => map
+                .get("Statement")
+                .cloned()
+                .or_value_error("IAM document is missing a \"Statement\" array")?,
+            _ => {
+                return Err(pyo3::exceptions::PyValueError::new_err(
+                    "IAM document must be a list of statements or an object with a \"Statement\" array",
+                ))
+            }
+        };
+        let statements: Vec<IamStatement> = serde_json::from_value(statements_json)
+            .or_value_error("failed to parse IAM statements")?;
+        let policies: Vec<Policy> = statements
+            .into_iter()
+            .map(statement_to_policy)
+            .collect::<PyResult<Vec<Policy>>>()?;
+        cedar_policy::PolicySet::from_policies(policies.iter().map(|p| p.to_cedar_policy()))
+            .or_value_error("failed to build policy set")
+            .map(|policy_set| PolicySet { policy_set })
+    }
+}
@@ -1,25 +1,36 @@
 use pyo3::prelude::*;
 
 mod authorizer;
+mod context;
+mod decision_log;
 mod entities;
 mod entity;
 mod entity_uid;
 mod errors;
 mod format_policies;
+mod iam;
+mod partial_request;
+mod partial_response;
 mod policy;
 mod policy_set;
 mod request;
 mod response;
 mod schema;
+mod template;
 use crate::authorizer::*;
+use crate::context::*;
+use crate::decision_log::*;
 use crate::entities::*;
 use crate::entity::*;
 use crate::entity_uid::*;
+use crate::partial_request::*;
+use crate::partial_response::*;
 use crate::policy::*;
 use crate::policy_set::*;
 use crate::request::*;
 use crate::response::*;
 use crate::schema::*;
+use crate::template::*;
 
 /// A Python module implemented in Rust.
 #[pymodule(name = " _lib", module = "cedar")]
@@ -30,14 +41,22 @@ fn setup_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<Entities>()?;
     m.add_class::<PolicySet>()?;
     m.add_class::<Policy>()?;
+    m.add_class::<Template>()?;
+    m.add_class::<SlotId>()?;
     m.add_class::<ValidationResult>()?;
+    m.add_class::<ValidationMode>()?;
     m.add_class::<Schema>()?;
     m.add_class::<Authorizer>()?;
+    m.add_class::<DecisionLogEntry>()?;
+    m.add_class::<Context>()?;
     m.add_class::<Request>()?;
+    m.add_class::<PartialRequest>()?;
+    m.add_class::<PartialResponse>()?;
     m.add_class::<Decision>()?;
     m.add_class::<Diagnostics>()?;
     m.add_class::<Response>()?;
     m.add_function(wrap_pyfunction!(is_authorized, m)?)?;
+    m.add_function(wrap_pyfunction!(is_authorized_partial, m)?)?;
     m.add_function(wrap_pyfunction!(is_authorized_batch, m)?)?;
     m.add_function(wrap_pyfunction!(format_policies::format_policies, m)?)?;
     Ok(())
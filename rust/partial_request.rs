@@ -0,0 +1,113 @@
+use pyo3::prelude::*;
+
+use crate::{context::Context, entity_uid::EntityUid, errors::IntoPyErr, schema::Schema};
+
+/// Authorization request where the principal, action, resource and/or
+/// context may be left unknown for partial evaluation.
+///
+/// Parameters:
+///     principal: the principal to authorize, or None if unknown
+///     action: the action to authorize principal to perform, or None if unknown
+///     resource: the resource to authorize principal to take action on, or None if unknown
+///     context: the context for this request, or None if unknown
+///     correlation_id: an optional correlation id that will be copied to the PartialResponse
+///
+/// Tip:
+///     This is the partial-evaluation counterpart of
+///     [Request][cedar.Request]. Leave a field `None` to ask the
+///     authorizer to evaluate as far as it can without it, e.g. leaving
+///     `resource` unknown to answer "which resources can this principal
+///     see?" in a single pass instead of one [is_authorized][cedar.Authorizer.is_authorized]
+///     call per candidate resource.
+#[pyclass(module = "cedar._lib")]
+#[derive(Clone)]
+pub struct PartialRequest {
+    pub principal: Option<EntityUid>,
+    pub action: Option<EntityUid>,
+    pub resource: Option<EntityUid>,
+    pub context: Option<Context>,
+    pub correlation_id: Option<String>,
+}
+
+impl PartialRequest {
+    pub fn make_cedar_request(&self, schema: Option<&Schema>) -> PyResult<cedar_policy::Request> {
+        // `RequestBuilder` defaults every field to Unknown; calling a
+        // setter at all (even with a concrete value) opts that field out of
+        // partial evaluation, so fields left `None` here must not be
+        // touched rather than passed through as `None`.
+        let mut builder = cedar_policy::Request::builder();
+        if let Some(principal) = &self.principal {
+            builder = builder.principal(Some(principal.make_cedar_euid()?));
+        }
+        if let Some(action) = &self.action {
+            builder = builder.action(Some(action.make_cedar_euid()?));
+        }
+        if let Some(resource) = &self.resource {
+            builder = builder.resource(Some(resource.make_cedar_euid()?));
+        }
+        if let Some(context) = &self.context {
+            let cedar_context =
+                context.make_cedar_context_for_action(schema, self.action.as_ref())?;
+            builder = builder.context(cedar_context);
+        }
+        match schema {
+            Some(schema) => builder
+                .schema(&schema.schema)
+                .build()
+                .or_value_error("failed to create partial request"),
+            None => Ok(builder.build()),
+        }
+    }
+}
+
+#[pymethods]
+impl PartialRequest {
+    /// Create a new partial authorization request.
+    #[new]
+    #[pyo3(signature = (*, principal = None, action = None, resource = None, context = None, correlation_id = None))]
+    fn new_py(
+        principal: Option<EntityUid>,
+        action: Option<EntityUid>,
+        resource: Option<EntityUid>,
+        context: Option<Context>,
+        correlation_id: Option<String>,
+    ) -> Self {
+        PartialRequest {
+            principal,
+            action,
+            resource,
+            context,
+            correlation_id,
+        }
+    }
+
+    /// Get principal for this request, if known.
+    #[getter]
+    fn principal(&self) -> Option<EntityUid> {
+        self.principal.clone()
+    }
+
+    /// Get action for this request, if known.
+    #[getter]
+    fn action(&self) -> Option<EntityUid> {
+        self.action.clone()
+    }
+
+    /// Get resource for this request, if known.
+    #[getter]
+    fn resource(&self) -> Option<EntityUid> {
+        self.resource.clone()
+    }
+
+    /// Get the context for this request, if known.
+    #[getter]
+    fn context(&self) -> Option<Context> {
+        self.context.clone()
+    }
+
+    /// Get the correlation ID associated to this request.
+    #[getter]
+    fn correlation_id(&self) -> Option<String> {
+        self.correlation_id.clone()
+    }
+}
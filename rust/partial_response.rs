@@ -0,0 +1,134 @@
+use std::collections::{HashMap, HashSet};
+
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::errors::*;
+use crate::response::Decision;
+
+/// Result of a partial authorization request.
+///
+/// When some parts of the request or context are left unknown, the
+/// authorizer evaluates every policy as far as it can. If the decision
+/// can already be determined, it is reported exactly like a normal
+/// [Response][cedar.Response]. Otherwise, the policies whose conditions
+/// still reference an unknown are returned as residuals, serialized back
+/// into Cedar text, keyed by policy id, so callers can store them and
+/// re-evaluate once the unknowns are filled in.
+///
+/// Parameters:
+///     decision: the decision, if already determined
+///     satisfied: ids of the policies already known to be satisfied
+///     errored: ids of the policies that raised an evaluation error (e.g. an
+///         unknown used where a concrete value was required), whether or not
+///         a decision was reached
+///     residuals: residual policies that still depend on an unknown, as Cedar text keyed by policy id
+///     correlation_id: an optional correlation id as a string
+///
+/// Note:
+///     Cedar does not surface a "definitely unsatisfied" policy set: a
+///     policy either contributed to the decision ([satisfied][cedar.PartialResponse.satisfied]),
+///     errored out, or still depends on an unknown ([residuals][cedar.PartialResponse.residuals]).
+#[pyclass(module = "cedar._lib")]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct PartialResponse {
+    decision: Option<Decision>,
+    satisfied: HashSet<String>,
+    errored: HashSet<String>,
+    residuals: HashMap<String, String>,
+    correlation_id: Option<String>,
+}
+
+impl PartialResponse {
+    pub fn from_cedar_partial_response(
+        response: cedar_policy::PartialResponse,
+        correlation_id: Option<String>,
+    ) -> Self {
+        // `decision()` is only `Some` once every policy that could affect
+        // the outcome has been resolved one way or another; otherwise Cedar
+        // still reports which policies it was able to resolve (definitely
+        // satisfied, definitely errored) and leaves the rest as residuals
+        // that still depend on an unknown.
+        let decision = response.decision().map(|decision| match decision {
+            cedar_policy::Decision::Allow => Decision::Allow,
+            cedar_policy::Decision::Deny => Decision::Deny,
+        });
+        PartialResponse {
+            decision,
+            satisfied: response
+                .definitely_satisfied()
+                .map(|policy| policy.id().to_string())
+                .collect(),
+            errored: response
+                .definitely_errored()
+                .map(|id| id.to_string())
+                .collect(),
+            residuals: response
+                .nontrivial_residuals()
+                .map(|policy| (policy.id().to_string(), policy.to_string()))
+                .collect(),
+            correlation_id,
+        }
+    }
+}
+
+#[pymethods]
+impl PartialResponse {
+    /// Whether the authorizer was able to fully determine a decision.
+    ///
+    /// Returns:
+    ///     True if [decision][cedar.PartialResponse.decision] is not None
+    #[getter]
+    fn is_concrete(&self) -> bool {
+        self.decision.is_some()
+    }
+
+    /// Get the decision, if the authorizer was able to fully determine one.
+    #[getter]
+    fn decision(&self) -> Option<Decision> {
+        self.decision.clone()
+    }
+
+    /// Get the ids of policies already known to be satisfied.
+    #[getter]
+    fn satisfied(&self) -> HashSet<String> {
+        self.satisfied.clone()
+    }
+
+    /// Get the ids of policies that raised an evaluation error (e.g. an
+    /// unknown used where a concrete value was required).
+    ///
+    /// This may be non-empty whether or not [decision][cedar.PartialResponse.decision]
+    /// is None: a policy that errored does not prevent a decision from being reached.
+    #[getter]
+    fn errored(&self) -> HashSet<String> {
+        self.errored.clone()
+    }
+
+    /// Get the residual policies, as Cedar text keyed by policy id.
+    ///
+    /// These still reference one or more unknowns and must be
+    /// re-evaluated (e.g. via [PolicySet.from_string][cedar.PolicySet.from_string]
+    /// plus a fresh [Authorizer.is_authorized][cedar.Authorizer.is_authorized] call)
+    /// once the unknowns are filled in.
+    #[getter]
+    fn residuals(&self) -> HashMap<String, String> {
+        self.residuals.clone()
+    }
+
+    /// Get the correlation ID which was provided in the partial request (may be None)
+    #[getter]
+    fn correlation_id(&self) -> Option<String> {
+        self.correlation_id.clone()
+    }
+
+    /// Serialize the partial response to a python dictionary.
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        pythonize::pythonize(py, self).or_value_error("failed to serialize to dict")
+    }
+
+    /// Serialize the partial response to a JSON string.
+    fn to_json(&self) -> PyResult<String> {
+        serde_json::to_string(&self).or_value_error("failed to serialize to JSON")
+    }
+}
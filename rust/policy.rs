@@ -5,6 +5,12 @@ use std::str::FromStr;
 
 use crate::errors::*;
 
+/// Parse an optional policy id string into a `PolicyId`.
+pub(crate) fn parse_policy_id(id: Option<String>) -> PyResult<Option<cedar_policy::PolicyId>> {
+    id.map(|id| cedar_policy::PolicyId::from_str(&id).or_value_error("failed to parse policy id"))
+        .transpose()
+}
+
 /// Clones the provided policy with its ID set to the value of the annotation
 /// indicated by `key` if it exists.
 pub fn clone_policy_with_id_from_annotation_optional(
@@ -52,14 +58,11 @@ impl Policy {
     ///     * <https://docs.cedarpolicy.com/policies/syntax-policy.html>
     #[staticmethod]
     #[pyo3(signature = (text, id = None))]
-    fn from_string(text: &str, id: Option<String>) -> PyResult<Self> {
+    pub fn from_string(text: &str, id: Option<String>) -> PyResult<Self> {
+        let id = parse_policy_id(id)?;
         match id {
-            Some(id) => cedar_policy::Policy::from_str(&text).map(|policy| {
-                policy.new_id(
-                    cedar_policy::PolicyId::from_str(&id).unwrap_or_else(|never| match never {}),
-                )
-            }),
-            None => cedar_policy::Policy::from_str(&text)
+            Some(id) => cedar_policy::Policy::from_str(text).map(|policy| policy.new_id(id)),
+            None => cedar_policy::Policy::from_str(text)
                 .map(|policy| clone_policy_with_id_from_annotation_optional(&policy, "id")),
         }
         .map(|policy| Policy { policy })
@@ -79,16 +82,12 @@ impl Policy {
     #[staticmethod]
     #[pyo3(signature = (text, id = None))]
     fn from_json(text: &str, id: Option<String>) -> PyResult<Self> {
-        serde_json::from_str(&text)
+        let id = parse_policy_id(id)?;
+        serde_json::from_str(text)
             .or_value_error("failed to deserialize JSON")
             .and_then(|value| {
-                cedar_policy::Policy::from_json(
-                    id.map(|v| {
-                        cedar_policy::PolicyId::from_str(&v).unwrap_or_else(|never| match never {})
-                    }),
-                    value,
-                )
-                .or_value_error("failed to parse policy from JSON")
+                cedar_policy::Policy::from_json(id, value)
+                    .or_value_error("failed to parse policy from JSON")
             })
             .map(|policy| Policy { policy })
     }
@@ -106,16 +105,12 @@ impl Policy {
     #[staticmethod]
     #[pyo3(signature = (values, /, *, id = None))]
     fn from_dict(values: &Bound<'_, PyDict>, id: Option<String>) -> PyResult<Self> {
+        let id = parse_policy_id(id)?;
         pythonize::depythonize(values)
             .or_value_error("failed to parse dict")
             .and_then(|value| {
-                cedar_policy::Policy::from_json(
-                    id.map(|v| {
-                        cedar_policy::PolicyId::from_str(&v).unwrap_or_else(|never| match never {})
-                    }),
-                    value,
-                )
-                .or_value_error("failed to parse json value")
+                cedar_policy::Policy::from_json(id, value)
+                    .or_value_error("failed to parse json value")
             })
             .map(|policy| Policy { policy })
     }
@@ -160,6 +155,7 @@ impl Policy {
     ///
     /// See also:
     ///     * <https://docs.cedarpolicy.com/policies/syntax-policy.html>
+    #[allow(clippy::inherent_to_string)]
     fn to_string(&self) -> String {
         self.policy.to_string()
     }
@@ -1,10 +1,13 @@
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
+use std::collections::HashMap;
 use std::str::FromStr;
 
+use crate::entity_uid::EntityUid;
 use crate::errors::*;
 use crate::policy::*;
+use crate::template::*;
 
 /// Constructs a new `PolicySet` containing a copy of each _static_ policy in
 /// `policy_set` with its ID set to the value of the annotation indicated by
@@ -86,7 +89,7 @@ impl PolicySet {
     #[staticmethod]
     #[pyo3(signature = (text, /))]
     fn from_string(text: &str) -> PyResult<PolicySet> {
-        cedar_policy::PolicySet::from_str(&text)
+        cedar_policy::PolicySet::from_str(text)
             .map(|policy_set| clone_policies_with_id_from_annotation_optional(&policy_set, "id"))
             .map(|policy_set| PolicySet { policy_set })
             .or_value_error("failed to parse policy set")
@@ -157,6 +160,7 @@ impl PolicySet {
     ///
     /// See also:
     ///     * <https://docs.cedarpolicy.com/policies/syntax-policy.html>
+    #[allow(clippy::inherent_to_string)]
     fn to_string(&self) -> String {
         self.policy_set.to_string()
     }
@@ -200,4 +204,63 @@ impl PolicySet {
             .map(|policy| Policy::from_cedar_policy(policy.clone()))
             .collect()
     }
+
+    /// Get templates registered in the policy set
+    ///
+    /// Returns:
+    ///     A list of templates
+    ///
+    /// See also:
+    ///     * <https://docs.cedarpolicy.com/policies/templates.html>
+    #[getter]
+    fn templates(&self) -> Vec<Template> {
+        self.policy_set
+            .templates()
+            .map(|template| Template::from_cedar_template(template.clone()))
+            .collect()
+    }
+
+    /// Register a template into the policy set.
+    ///
+    /// Parameters:
+    ///     template: the template to register
+    ///
+    /// See also:
+    ///     * <https://docs.cedarpolicy.com/policies/templates.html>
+    fn add_template(&mut self, template: &Template) -> PyResult<()> {
+        self.policy_set
+            .add_template(template.template.clone())
+            .or_value_error("failed to add template")
+    }
+
+    /// Instantiate a template into a concrete, linked policy.
+    ///
+    /// Parameters:
+    ///     template_id: the id of a template already registered in the policy set
+    ///     new_id: the id to give the newly linked policy
+    ///     values: a dict mapping [SlotId][cedar.SlotId]s to entity uids
+    ///
+    /// See also:
+    ///     * <https://docs.cedarpolicy.com/policies/templates.html>
+    #[pyo3(signature = (template_id, new_id, values, /))]
+    fn link(
+        &mut self,
+        template_id: String,
+        new_id: String,
+        values: &Bound<'_, PyDict>,
+    ) -> PyResult<()> {
+        let template_id = cedar_policy::PolicyId::from_str(&template_id)
+            .or_value_error("failed to parse template id")?;
+        let new_id =
+            cedar_policy::PolicyId::from_str(&new_id).or_value_error("failed to parse policy id")?;
+        let mut slots: HashMap<cedar_policy::SlotId, cedar_policy::EntityUid> = HashMap::new();
+        for (key, value) in values.iter() {
+            let slot_id: SlotId = key.extract()?;
+            let euid: EntityUid = value.extract()?;
+            slots.insert(slot_id.to_cedar_slot_id(), euid.make_cedar_euid()?);
+        }
+        self.policy_set
+            .link(template_id, new_id, slots)
+            .or_value_error("failed to link template")
+    }
 }
@@ -1,7 +1,7 @@
 use pyo3::{prelude::*, types::PyDict};
 use serde::{Deserialize, Serialize};
 
-use crate::{entity_uid::EntityUid, errors::IntoPyErr, schema::Schema};
+use crate::{context::Context, entity_uid::EntityUid, errors::IntoPyErr, schema::Schema};
 
 /// Cedar authorization request.
 ///
@@ -9,17 +9,14 @@ use crate::{entity_uid::EntityUid, errors::IntoPyErr, schema::Schema};
 ///     principal: the principal to authorize
 ///     action: the action to authorize principal to perform
 ///     resource: the resource to authorize principal to take action on
-///     context: the context for this auhtorization request
+///     context: the context for this auhtorization request, as a [Context][cedar.Context] or a plain dict
 ///
 /// Tip:
 ///     An authorization request is a tuple <P, A, R, C> where
-///
-///     * P is the principal EntityUid,
-///     * A is the action EntityUid,
-///     * R is the resource EntityUid, and
-///     * C is the request Context record.
-///
-///     It represents an authorization request asking the question, “Can this principal take this action on this resource in this context?”
+///     P is the principal EntityUid, A is the action EntityUid, R is the
+///     resource EntityUid, and C is the request Context record. It
+///     represents an authorization request asking the question, "Can this
+///     principal take this action on this resource in this context?"
 #[pyclass(module = "cedar._lib")]
 #[derive(Clone, Serialize, Deserialize)]
 pub struct Request {
@@ -29,30 +26,42 @@ pub struct Request {
     pub action: EntityUid,
     /// Resource for the request, e.g., File::"myfile.txt"
     pub resource: EntityUid,
-    /// A JSON string representing the context for the request.
+    /// The context for the request.
     /// Should be a (possibly empty) map from keys to values.
-    pub context: Option<serde_json::Value>,
+    pub context: Option<Context>,
     /// An optional correlation id that will be copied to the AuthResponse
     pub correlation_id: Option<String>,
 }
 
 impl Request {
+    /// Accept either a [Context][cedar.Context] instance or a plain dict for
+    /// the `context` constructor argument, so existing call sites built
+    /// around `Request(..., context={...})` keep working now that
+    /// [Context][cedar.Context] exists.
+    fn context_from_any(value: &Bound<'_, PyAny>) -> PyResult<Context> {
+        if let Ok(context) = value.extract::<Context>() {
+            return Ok(context);
+        }
+        if let Ok(dict) = value.downcast::<PyDict>() {
+            return Context::from_dict(dict);
+        }
+        Err(pyo3::exceptions::PyTypeError::new_err(
+            "context must be a Context or a dict",
+        ))
+    }
+
     pub fn make_cedar_request(&self, schema: Option<&Schema>) -> PyResult<cedar_policy::Request> {
         let cedar_schema = schema.map(|s| &s.schema);
         // Validate context
         let cedar_context = match &self.context {
-            Some(json) => cedar_policy::Context::from_json_value(
-                json.clone(),
-                cedar_schema.zip(Some(&self.action.make_cedar_euid()?)),
-            )
-            .or_value_error("failed to parse context")?,
+            Some(context) => context.make_cedar_context(schema, &self.action)?,
             None => cedar_policy::Context::empty(),
         };
         // Make request
         cedar_policy::Request::new(
-            self.principal.make_cedar_euid()?,
-            self.action.make_cedar_euid()?,
-            self.resource.make_cedar_euid()?,
+            Some(self.principal.make_cedar_euid()?),
+            Some(self.action.make_cedar_euid()?),
+            Some(self.resource.make_cedar_euid()?),
             cedar_context,
             cedar_schema,
         )
@@ -69,17 +78,10 @@ impl Request {
         principal: EntityUid,
         action: EntityUid,
         resource: EntityUid,
-        context: Option<&Bound<'_, PyDict>>,
+        context: Option<&Bound<'_, PyAny>>,
         correlation_id: Option<String>,
     ) -> PyResult<Request> {
-        let context = match context {
-            Some(context) => {
-                let values: serde_json::Value = pythonize::depythonize(context)
-                    .or_value_error("failed to parse context from dict")?;
-                Some(values)
-            }
-            None => None,
-        };
+        let context = context.map(Request::context_from_any).transpose()?;
         Ok(Request {
             principal,
             action,
@@ -122,14 +124,21 @@ impl Request {
     }
 
     /// Get the context for this request as a python dictionary.
+    ///
+    /// See also:
+    ///     * [context_obj][cedar.Request.context_obj] to get it as a [Context][cedar.Context] instance instead
     #[getter]
     fn context<'py>(&self, py: Python<'py>) -> PyResult<Option<Bound<'py, PyAny>>> {
-        match &self.context {
-            Some(context) => pythonize::pythonize(py, &context)
-                .or_value_error("failed to serialize context to dict")
-                .map(|v| Some(v)),
-            None => Ok(None),
-        }
+        self.context
+            .as_ref()
+            .map(|context| context.to_dict(py))
+            .transpose()
+    }
+
+    /// Get the context for this request as a [Context][cedar.Context] instance.
+    #[getter]
+    fn context_obj(&self) -> Option<Context> {
+        self.context.clone()
     }
 
     /// Get the correlation ID associated to this request.
@@ -61,11 +61,7 @@ impl Response {
                 errors: response
                     .diagnostics()
                     .errors()
-                    .map(|e| match e {
-                        cedar_policy::AuthorizationError::PolicyEvaluationError(e) => {
-                            e.policy_id().to_string()
-                        }
-                    })
+                    .map(|e| e.to_string())
                     .collect(),
             },
         }
@@ -87,7 +83,7 @@ impl Response {
             decision: Decision::Deny,
             diagnostics: Diagnostics {
                 reason: HashSet::new(),
-                errors: errors,
+                errors,
             },
             correlation_id,
         }
@@ -102,11 +98,11 @@ impl Response {
         diagnostics: Diagnostics,
         correlation_id: Option<String>,
     ) -> Self {
-        return Response {
+        Response {
             decision,
             correlation_id,
             diagnostics,
-        };
+        }
     }
 
     /// Create a new response from a JSON string.
@@ -135,13 +131,13 @@ impl Response {
 
     /// Get the decision from the response.
     #[getter]
-    fn decision(&self) -> Decision {
+    pub fn decision(&self) -> Decision {
         self.decision.clone()
     }
 
     /// Get diagnostics associated to decision.
     #[getter]
-    fn diagnostics(&self) -> Diagnostics {
+    pub fn diagnostics(&self) -> Diagnostics {
         self.diagnostics.clone()
     }
 
@@ -181,7 +177,7 @@ impl Diagnostics {
                         .map(|r: &String| cedar_policy::PolicyId::new(r))
                         .collect()
                 })
-                .unwrap_or(HashSet::new()),
+                .unwrap_or_default(),
             errors: errors.unwrap_or(vec![]),
         }
     }
@@ -213,14 +209,14 @@ impl Diagnostics {
     /// Get the PolicyIds of the policies that contributed to the decision.
     /// If no policies applied to the request, this set will be empty.
     #[getter]
-    fn reasons(&self) -> HashSet<String> {
+    pub fn reasons(&self) -> HashSet<String> {
         self.reason.iter().map(|r| r.to_string()).collect()
     }
 
     /// Get the errors that occurred during authorization.
     /// The errors should be treated as unordered, since policies may be evaluated in any order.
     #[getter]
-    fn errors(&self) -> Vec<String> {
+    pub fn errors(&self) -> Vec<String> {
         self.errors.clone()
     }
 }
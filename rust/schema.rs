@@ -3,17 +3,135 @@ use pyo3::types::PyDict;
 use serde::Deserialize;
 use serde::Serialize;
 
+use std::fmt;
 use std::iter;
 use std::str::FromStr;
 
 use crate::errors::*;
 use crate::policy_set::*;
 
+/// Validation mode controlling how strictly policies are checked against a schema.
+///
+/// See also:
+///     * <https://docs.cedarpolicy.com/policies/validation.html>
+#[pyclass(eq, frozen, hash, str, module = "cedar._lib")]
+#[derive(Debug, Hash, Clone, PartialEq, Eq)]
+pub enum ValidationMode {
+    /// Validate policies using Cedar's strict typing rules.
+    Strict,
+    /// Validate policies using Cedar's permissive typing rules, allowing
+    /// heterogeneous sets and unknown attributes that strict mode would reject.
+    Permissive,
+}
+
+impl ValidationMode {
+    fn to_cedar_validation_mode(&self) -> cedar_policy::ValidationMode {
+        match self {
+            ValidationMode::Strict => cedar_policy::ValidationMode::Strict,
+            ValidationMode::Permissive => cedar_policy::ValidationMode::Permissive,
+        }
+    }
+}
+
+impl fmt::Display for ValidationMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ValidationMode::Strict => write!(f, "strict"),
+            ValidationMode::Permissive => write!(f, "permissive"),
+        }
+    }
+}
+
+/// Extract the byte offset and length of a diagnostic's source span, when Cedar exposes one.
+fn span_from_loc(location: &cedar_policy::SourceLocation) -> (Option<usize>, Option<usize>) {
+    match (location.range_start(), location.range_end()) {
+        (Some(start), Some(end)) => (Some(start), Some(end.saturating_sub(start))),
+        _ => (None, None),
+    }
+}
+
+/// Derive a stable, machine-readable code from a diagnostic's variant name.
+///
+/// `ValidationError`/`ValidationWarning` are opaque structs, not enums, so
+/// `Debug`-formatting one of them directly would just print the struct
+/// name for every diagnostic. The actual variant lives on the inner kind
+/// enum, so callers must pass `error.error_kind()` / `warning.warning_kind()`
+/// (or equivalent) rather than the diagnostic itself.
+fn diagnostic_kind<T: fmt::Debug>(kind: &T) -> String {
+    format!("{kind:?}")
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Convert a Cedar schema attribute/type JSON value into a JSON Schema (Draft 2020-12) node.
+fn cedar_type_to_json_schema(ty: &serde_json::Value) -> serde_json::Value {
+    match ty.get("type").and_then(|v| v.as_str()).unwrap_or("") {
+        "Long" => serde_json::json!({"type": "integer"}),
+        "String" => serde_json::json!({"type": "string"}),
+        "Boolean" => serde_json::json!({"type": "boolean"}),
+        "Set" => {
+            let element = ty
+                .get("element")
+                .cloned()
+                .unwrap_or(serde_json::json!({"type": "String"}));
+            serde_json::json!({"type": "array", "items": cedar_type_to_json_schema(&element)})
+        }
+        "Record" => record_to_json_schema(ty),
+        "Entity" => {
+            let name = ty.get("name").and_then(|v| v.as_str()).unwrap_or("");
+            serde_json::json!({"type": "string", "pattern": format!("^{name}::\".*\"$")})
+        }
+        "Extension" => {
+            let name = ty.get("name").and_then(|v| v.as_str()).unwrap_or("");
+            serde_json::json!({"type": "string", "format": name})
+        }
+        _ => serde_json::json!({"type": "string"}),
+    }
+}
+
+/// Convert a Cedar `Record` type (entity shape or action context) into a JSON Schema object node.
+///
+/// Optional attributes are omitted from `required`, and closed records
+/// (`additionalAttributes: false`) set `additionalProperties: false`.
+fn record_to_json_schema(ty: &serde_json::Value) -> serde_json::Value {
+    let mut properties = serde_json::Map::new();
+    let mut required: Vec<serde_json::Value> = Vec::new();
+    if let Some(attrs) = ty.get("attributes").and_then(|v| v.as_object()) {
+        for (name, attr) in attrs {
+            properties.insert(name.clone(), cedar_type_to_json_schema(attr));
+            let is_required = attr.get("required").and_then(|v| v.as_bool()).unwrap_or(true);
+            if is_required {
+                required.push(serde_json::Value::String(name.clone()));
+            }
+        }
+    }
+    // Cedar records and entity shapes are closed by default: only a schema
+    // that explicitly sets `additionalAttributes: true` allows extra keys.
+    let additional_attributes = ty
+        .get("additionalAttributes")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+    serde_json::json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": additional_attributes,
+    })
+}
+
 #[pyclass(eq, frozen, hash, module = "cedar._lib")]
 #[derive(PartialEq, Debug, Clone, Hash, Serialize, Deserialize)]
 pub struct ValidationError {
     pub policy_id: String,
     pub error: String,
+    /// A stable, machine-readable code for the kind of error, derived from the underlying diagnostic variant.
+    pub kind: String,
+    /// Byte offset of the offending span within the policy text, when available.
+    pub offset: Option<usize>,
+    /// Byte length of the offending span within the policy text, when available.
+    pub length: Option<usize>,
 }
 
 #[pyclass(eq, frozen, hash, module = "cedar._lib")]
@@ -21,6 +139,12 @@ pub struct ValidationError {
 pub struct ValidationWarning {
     pub policy_id: String,
     pub warning: String,
+    /// A stable, machine-readable code for the kind of warning, derived from the underlying diagnostic variant.
+    pub kind: String,
+    /// Byte offset of the offending span within the policy text, when available.
+    pub offset: Option<usize>,
+    /// Byte length of the offending span within the policy text, when available.
+    pub length: Option<usize>,
 }
 
 /// Output of policy validation against a schema.
@@ -46,16 +170,28 @@ impl ValidationResult {
             passwed_without_warning: result.validation_passed_without_warnings(),
             errors: result
                 .validation_errors()
-                .map(|e| ValidationError {
-                    policy_id: e.policy_id().to_string(),
-                    error: e.to_string(),
+                .map(|e| {
+                    let (offset, length) = span_from_loc(e.location());
+                    ValidationError {
+                        policy_id: e.location().policy_id().to_string(),
+                        error: e.to_string(),
+                        kind: diagnostic_kind(e.error_kind()),
+                        offset,
+                        length,
+                    }
                 })
                 .collect(),
             warnings: result
                 .validation_warnings()
-                .map(|w| ValidationWarning {
-                    policy_id: w.policy_id().to_string(),
-                    warning: w.to_string(),
+                .map(|w| {
+                    let (offset, length) = span_from_loc(w.location());
+                    ValidationWarning {
+                        policy_id: w.location().policy_id().to_string(),
+                        warning: w.to_string(),
+                        kind: diagnostic_kind(w.warning_kind()),
+                        offset,
+                        length,
+                    }
                 })
                 .collect(),
             msg: result.to_string(),
@@ -119,6 +255,7 @@ impl ValidationResult {
     ///
     /// Returns:
     ///     A string which can be used in error messages.
+    #[allow(clippy::inherent_to_string)]
     fn to_string(&self) -> String {
         self.msg.clone()
     }
@@ -135,21 +272,44 @@ impl ValidationResult {
 /// See also:
 ///     * <https://docs.cedarpolicy.com/schema/schema.html>
 #[pyclass(module = "cedar._lib")]
-#[derive(Clone)]
 pub struct Schema {
-    fragment: cedar_policy::SchemaFragment,
+    // `cedar_policy::SchemaFragment` holds onto unresolved type-def closures
+    // that aren't `Send`, which would make `Schema` (and anything embedding
+    // it, like `Authorizer`) unusable from the threads rayon's batch
+    // authorization spawns. Keep the fragment's JSON form instead, and
+    // re-parse a `SchemaFragment` on demand for the methods (`to_string`)
+    // that need one.
+    fragment_json: serde_json::Value,
     validator: cedar_policy::Validator,
     pub schema: cedar_policy::Schema,
 }
 
+// `cedar_policy::Validator` doesn't implement `Clone`, but it's a cheap
+// wrapper around the (`Clone`) resolved `cedar_policy::Schema`, so rebuild
+// it instead of deriving.
+impl Clone for Schema {
+    fn clone(&self) -> Self {
+        Schema {
+            fragment_json: self.fragment_json.clone(),
+            validator: cedar_policy::Validator::new(self.schema.clone()),
+            schema: self.schema.clone(),
+        }
+    }
+}
+
 impl Schema {
     fn from_cedar_fragment(fragment: cedar_policy::SchemaFragment) -> PyResult<Self> {
-        let schema = cedar_policy::Schema::from_schema_fragments(iter::once(fragment.clone()))
-            // Zip schema with fragment so that we can serialize to string later
+        let fragment_json = fragment
+            .to_json_value()
+            .or_value_error("failed to serialize schema fragment")?;
+        let fragment_for_schema =
+            cedar_policy::SchemaFragment::from_json_value(fragment_json.clone())
+                .or_value_error("failed to parse schema from fragment")?;
+        let schema = cedar_policy::Schema::from_schema_fragments(iter::once(fragment_for_schema))
             .or_value_error("failed to parse schema from fragment")?;
         let validator = cedar_policy::Validator::new(schema.clone());
         Ok(Schema {
-            fragment,
+            fragment_json,
             validator,
             schema,
         })
@@ -168,7 +328,7 @@ impl Schema {
     #[staticmethod]
     #[pyo3(signature = (text, /))]
     fn from_json(text: &str) -> PyResult<Schema> {
-        cedar_policy::SchemaFragment::from_json_str(&text)
+        cedar_policy::SchemaFragment::from_json_str(text)
             .or_value_error("failed to parse schema from json")
             .and_then(Schema::from_cedar_fragment)
     }
@@ -183,7 +343,7 @@ impl Schema {
     #[staticmethod]
     #[pyo3(signature = (text, /))]
     fn from_string(text: &str) -> PyResult<Schema> {
-        cedar_policy::SchemaFragment::from_str(&text)
+        cedar_policy::SchemaFragment::from_str(text)
             .or_value_error("failed to parse schema from string")
             .and_then(Schema::from_cedar_fragment)
     }
@@ -215,13 +375,7 @@ impl Schema {
     /// See also:
     ///     * <https://docs.cedarpolicy.com/schema/json-schema.html>
     fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
-        self.fragment
-            .clone()
-            .to_json_value()
-            .or_value_error("failed to serialize fragment to json values")
-            .and_then(|values| {
-                pythonize::pythonize(py, &values).or_value_error("failed to serialize to dict")
-            })
+        pythonize::pythonize(py, &self.fragment_json).or_value_error("failed to serialize to dict")
     }
 
     /// Serialize schema to JSON string.
@@ -232,9 +386,7 @@ impl Schema {
     /// See also:
     ///     * <https://docs.cedarpolicy.com/schema/json-schema.html>
     fn to_json(&self) -> PyResult<String> {
-        self.fragment
-            .to_json_string()
-            .or_value_error("failed to encode schema")
+        serde_json::to_string(&self.fragment_json).or_value_error("failed to encode schema")
     }
 
     /// Serialize schema to cedar language string.
@@ -245,26 +397,103 @@ impl Schema {
     /// See also:
     ///     * <https://docs.cedarpolicy.com/schema/human-readable-schema.html>
     fn to_string(&self) -> PyResult<String> {
-        self.fragment
+        cedar_policy::SchemaFragment::from_json_value(self.fragment_json.clone())
+            .or_value_error("failed to parse schema fragment")?
             .to_cedarschema()
             .or_value_error("failed to encode schema")
     }
 
+    /// Generate a JSON Schema (Draft 2020-12) document describing entity
+    /// attributes and per-action context shapes.
+    ///
+    /// This lets Python applications validate incoming entity/context JSON
+    /// with an off-the-shelf JSON Schema validator before ever calling the
+    /// authorizer. The document itself has no root constraint: look up
+    /// `document["$defs"]["entities"][type_name]` or
+    /// `document["$defs"]["actions"][action_name]` (each also carries a
+    /// matching `$id` a validator's schema registry can resolve) to
+    /// validate a single entity's attributes or a single action's context.
+    ///
+    /// Returns:
+    ///     A python dictionary holding a JSON Schema document
+    ///
+    /// See also:
+    ///     * <https://json-schema.org/draft/2020-12/release-notes>
+    fn to_json_schema<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let namespaces = self
+            .fragment_json
+            .as_object()
+            .or_value_error("unexpected schema fragment shape")?;
+        let mut entities = serde_json::Map::new();
+        let mut actions = serde_json::Map::new();
+        let default_record = serde_json::json!({"type": "Record", "attributes": {}});
+        for (namespace, definition) in namespaces {
+            let qualify = |name: &str| -> String {
+                if namespace.is_empty() {
+                    name.to_string()
+                } else {
+                    format!("{namespace}::{name}")
+                }
+            };
+            if let Some(entity_types) = definition.get("entityTypes").and_then(|v| v.as_object()) {
+                for (name, entity_type) in entity_types {
+                    let shape = entity_type.get("shape").unwrap_or(&default_record);
+                    let qualified_name = qualify(name);
+                    let mut subschema = record_to_json_schema(shape);
+                    subschema["$id"] = serde_json::Value::String(format!("#entities/{qualified_name}"));
+                    entities.insert(qualified_name, subschema);
+                }
+            }
+            if let Some(action_defs) = definition.get("actions").and_then(|v| v.as_object()) {
+                for (name, action) in action_defs {
+                    let context = action
+                        .get("appliesTo")
+                        .and_then(|v| v.get("context"))
+                        .unwrap_or(&default_record);
+                    let qualified_name = qualify(name);
+                    let mut subschema = record_to_json_schema(context);
+                    subschema["$id"] = serde_json::Value::String(format!("#actions/{qualified_name}"));
+                    actions.insert(qualified_name, subschema);
+                }
+            }
+        }
+        // Each entity/action subschema carries its own `$id`, so a caller
+        // can hand `document["$defs"]["entities"][name]` (or resolve the
+        // `$id` directly through a validator's schema registry) to an
+        // off-the-shelf JSON Schema validator to check a single entity or
+        // action context; the top-level document itself has no root
+        // constraint and isn't meant to validate a whole request at once.
+        let document = serde_json::json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "$defs": {
+                "entities": entities,
+                "actions": actions,
+            },
+        });
+        pythonize::pythonize(py, &document).or_value_error("failed to serialize JSON schema to dict")
+    }
+
     /// Validate given policies against the schema.
     ///
     /// Parameters:
     ///     policies: the policies to validate
+    ///     mode: the validation mode to apply, defaults to [Strict][cedar.ValidationMode.Strict]
     ///
     /// Returns:
     ///     A validation result
     ///
     /// See also:
     ///     * <https://docs.cedarpolicy.com/policies/validation.html>
-    #[pyo3(signature = (policies, /))]
-    pub fn validate_policies(&self, policies: &PolicySet) -> ValidationResult {
+    #[pyo3(signature = (policies, /, mode = None))]
+    pub fn validate_policies(
+        &self,
+        policies: &PolicySet,
+        mode: Option<ValidationMode>,
+    ) -> ValidationResult {
+        let mode = mode.unwrap_or(ValidationMode::Strict);
         let result = self
             .validator
-            .validate(&policies.policy_set, cedar_policy::ValidationMode::Strict);
+            .validate(&policies.policy_set, mode.to_cedar_validation_mode());
         ValidationResult::from_cedar_validation_result(&result)
     }
 }
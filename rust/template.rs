@@ -0,0 +1,149 @@
+use pyo3::prelude::*;
+use std::fmt;
+
+use crate::errors::*;
+use crate::policy::parse_policy_id;
+
+/// Identifies a slot in a policy [Template][cedar.Template].
+///
+/// See also:
+///     * <https://docs.cedarpolicy.com/policies/templates.html>
+#[pyclass(eq, frozen, hash, str, module = "cedar._lib")]
+#[derive(Debug, Hash, Clone, PartialEq, Eq)]
+pub enum SlotId {
+    /// The `?principal` slot.
+    Principal,
+    /// The `?resource` slot.
+    Resource,
+}
+
+impl SlotId {
+    pub fn to_cedar_slot_id(&self) -> cedar_policy::SlotId {
+        match self {
+            SlotId::Principal => cedar_policy::SlotId::principal(),
+            SlotId::Resource => cedar_policy::SlotId::resource(),
+        }
+    }
+}
+
+impl fmt::Display for SlotId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SlotId::Principal => write!(f, "?principal"),
+            SlotId::Resource => write!(f, "?resource"),
+        }
+    }
+}
+
+/// Cedar policy template.
+///
+/// A template is a policy containing slots (`?principal` and/or
+/// `?resource`) that must be filled in with concrete entity uids before
+/// it can contribute to an authorization decision. Linking a template
+/// into a [PolicySet][cedar.PolicySet] produces a concrete policy that
+/// can take part in authorization requests.
+///
+/// See also:
+///     * <https://docs.cedarpolicy.com/policies/templates.html>
+#[pyclass(module = "cedar._lib")]
+#[derive(Clone)]
+pub struct Template {
+    pub template: cedar_policy::Template,
+}
+
+impl Template {
+    pub fn from_cedar_template(template: cedar_policy::Template) -> Self {
+        Template { template }
+    }
+}
+
+#[pymethods]
+impl Template {
+    /// Create a template from a string in Cedar policy format.
+    ///
+    /// Parameters:
+    ///     text: a string in Cedar policy format, containing `?principal` and/or `?resource` slots
+    ///     id: an optional policy id for the template
+    ///
+    /// Returns:
+    ///     A template
+    ///
+    /// See also:
+    ///     * <https://docs.cedarpolicy.com/policies/templates.html>
+    #[staticmethod]
+    #[pyo3(signature = (text, id = None))]
+    fn from_string(text: &str, id: Option<String>) -> PyResult<Self> {
+        cedar_policy::Template::parse(id, text)
+            .or_value_error("failed to parse template from string")
+            .map(|template| Template { template })
+    }
+
+    /// Create a template from a string in JSON policy format.
+    ///
+    /// Parameters:
+    ///     text: a string in JSON policy format
+    ///     id: an optional policy id for the template
+    ///
+    /// Returns:
+    ///     A template
+    ///
+    /// See also:
+    ///     * <https://docs.cedarpolicy.com/policies/json-format.html>
+    #[staticmethod]
+    #[pyo3(signature = (text, id = None))]
+    fn from_json(text: &str, id: Option<String>) -> PyResult<Self> {
+        let id = parse_policy_id(id)?;
+        serde_json::from_str(text)
+            .or_value_error("failed to deserialize JSON")
+            .and_then(|value| {
+                cedar_policy::Template::from_json(id, value)
+                    .or_value_error("failed to parse template from JSON")
+            })
+            .map(|template| Template { template })
+    }
+
+    /// Serialize template into python dictionary.
+    ///
+    /// Returns:
+    ///     A python dictionary
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        self.template
+            .to_json()
+            .or_value_error("failed to encode template to JSON")
+            .and_then(|values| {
+                pythonize::pythonize(py, &values).or_value_error("failed to serialize to dict")
+            })
+    }
+
+    /// Serialize template into JSON string.
+    ///
+    /// Returns:
+    ///     A string in JSON policy format
+    fn to_json(&self) -> PyResult<String> {
+        self.template
+            .to_json()
+            .or_value_error("failed to encode template to JSON")
+            .and_then(|value| {
+                serde_json::to_string(&value)
+                    .or_value_error("failed to serialize JSON template to string")
+            })
+    }
+
+    /// Serialize template into Cedar policy string.
+    ///
+    /// Returns:
+    ///     A string in Cedar policy format
+    #[allow(clippy::inherent_to_string)]
+    fn to_string(&self) -> String {
+        self.template.to_string()
+    }
+
+    /// Get template ID as string.
+    ///
+    /// Returns:
+    ///     The template ID as string
+    #[getter]
+    fn template_id(&self) -> String {
+        self.template.id().to_string()
+    }
+}